@@ -3,6 +3,8 @@ use std::f32::consts::TAU;
 
 use bevy::color::palettes::css::GREEN;
 use bevy::color::palettes::css::WHITE;
+use bevy::core_pipeline::core_3d::Opaque3d;
+use bevy::core_pipeline::core_3d::Opaque3dBinKey;
 use bevy::core_pipeline::core_3d::Transparent3d;
 use bevy::ecs::query::QueryItem;
 use bevy::ecs::system::SystemParamItem;
@@ -20,15 +22,22 @@ use bevy::pbr::wireframe::WireframePlugin;
 use bevy::prelude::*;
 use bevy::render::Render;
 use bevy::render::RenderApp;
+use bevy::render::RenderPlugin;
 use bevy::render::RenderSet;
+use bevy::render::settings::RenderCreation;
+use bevy::render::settings::WgpuFeatures;
+use bevy::render::settings::WgpuSettings;
 use bevy::render::extract_component::ExtractComponent;
 use bevy::render::extract_component::ExtractComponentPlugin;
+use bevy::render::extract_resource::ExtractResource;
+use bevy::render::extract_resource::ExtractResourcePlugin;
 use bevy::render::mesh::MeshVertexBufferLayoutRef;
 use bevy::render::mesh::RenderMesh;
 use bevy::render::mesh::RenderMeshBufferInfo;
 use bevy::render::mesh::allocator::MeshAllocator;
 use bevy::render::render_asset::RenderAssets;
 use bevy::render::render_phase::AddRenderCommand;
+use bevy::render::render_phase::BinnedRenderPhaseType;
 use bevy::render::render_phase::DrawFunctions;
 use bevy::render::render_phase::PhaseItem;
 use bevy::render::render_phase::PhaseItemExtraIndex;
@@ -36,12 +45,16 @@ use bevy::render::render_phase::RenderCommand;
 use bevy::render::render_phase::RenderCommandResult;
 use bevy::render::render_phase::SetItemPipeline;
 use bevy::render::render_phase::TrackedRenderPass;
+use bevy::render::render_phase::ViewBinnedRenderPhases;
 use bevy::render::render_phase::ViewSortedRenderPhases;
 use bevy::render::render_resource::*;
 use bevy::render::renderer::RenderDevice;
+use bevy::render::renderer::RenderQueue;
 use bevy::render::sync_world::MainEntity;
 use bevy::render::view::ExtractedView;
 use bevy::render::view::NoFrustumCulling;
+use binary_greedy_meshing::CS;
+use binary_greedy_meshing::CS_P;
 use binary_greedy_meshing::CS_P3;
 use binary_greedy_meshing::Mesher;
 use binary_greedy_meshing::pad_linearize;
@@ -53,7 +66,17 @@ use bytemuck::Zeroable;
 fn main() {
     App::new()
         .add_plugins((
-            DefaultPlugins,
+            // `DrawGpuChunkBatch` issues one `multi_draw_indexed_indirect` call
+            // covering every chunk, with each chunk's `first_instance` non-zero,
+            // so both features below need to be requested up front.
+            DefaultPlugins.set(RenderPlugin {
+                render_creation: RenderCreation::Automatic(WgpuSettings {
+                    features: WgpuFeatures::MULTI_DRAW_INDIRECT
+                        | WgpuFeatures::INDIRECT_FIRST_INSTANCE,
+                    ..default()
+                }),
+                ..default()
+            }),
             CustomMaterialPlugin,
             WireframePlugin::default(),
         ))
@@ -67,86 +90,354 @@ fn main() {
             // Can be changed per mesh using the `WireframeColor` component.
             default_color: WHITE.into(),
         })
+        // Draws each opaque meshed quad's outward normal as a short debug line;
+        // flip to `true` to sanity-check meshing on large chunk volumes
+        // without the per-gizmo-call overhead of `Gizmos`.
+        .insert_resource(QuadDebugConfig { global: false })
         .add_systems(Startup, setup)
-        .add_systems(Update, (rotate, gizmos, move_camera, rotate_camera))
+        .add_systems(
+            Update,
+            (
+                rotate,
+                gizmos,
+                move_camera,
+                rotate_camera,
+                mesh_new_chunks,
+                remesh_dirty_chunks,
+            ),
+        )
         .run();
 }
 
-fn quads() -> Vec<InstanceData> {
+// ---------- Voxel chunks ----------
+
+// A single streamable chunk of voxels, addressed by integer chunk coordinates.
+// The voxel grid is stored padded (see `binary_greedy_meshing::CS_P3`), so
+// `Mesher::fast_mesh` can sample neighbours across the chunk border without
+// bounds checks.
+#[derive(Component)]
+struct VoxelChunk {
+    voxels: Vec<u16>,
+    coord: IVec3,
+    // Voxel ids meshed into the transparent quad stream instead of the
+    // opaque one. Empty by default.
+    transparent_voxels: BTreeSet<u16>,
+    // Set whenever `voxels` changes; cleared once `remesh_dirty_chunks`
+    // re-meshes the chunk.
+    dirty: bool,
+}
+
+impl VoxelChunk {
+    fn empty(coord: IVec3) -> Self {
+        Self {
+            voxels: vec![0u16; CS_P3],
+            coord,
+            transparent_voxels: BTreeSet::new(),
+            dirty: true,
+        }
+    }
+
+    // Sets a single chunk-local voxel and marks the chunk dirty so
+    // `remesh_dirty_chunks` picks it up on the next `Update`.
+    fn set_voxel(&mut self, x: usize, y: usize, z: usize, id: u16) {
+        self.voxels[pad_linearize(x, y, z)] = id;
+        self.dirty = true;
+    }
+}
+
+// A chunk's meshed quads, split by whether the source voxel is transparent.
+struct ChunkMesh {
+    opaque: Vec<InstanceData>,
+    transparent: Vec<InstanceData>,
+}
+
+// The chunk-local `(x, y, z)` corner and `(w, h)` extent baked into an
+// encoded quad, decoded the same way as `decode_quad` in
+// voxel_rendering_instancing_poc.wgsl (minus the face, which the caller
+// already knows from which of `mesher.quads`'s 6 arrays the quad came from).
+struct DecodedQuad {
+    x: usize,
+    y: usize,
+    z: usize,
+    w: usize,
+    h: usize,
+}
+
+fn decode_quad(quad: u64) -> DecodedQuad {
+    DecodedQuad {
+        x: (quad & 0x3F) as usize,
+        y: ((quad >> 6) & 0x3F) as usize,
+        z: ((quad >> 12) & 0x3F) as usize,
+        w: ((quad >> 18) & 0x3F) as usize + 1,
+        h: ((quad >> 24) & 0x3F) as usize + 1,
+    }
+}
+
+// Mirrors FACE_NORMALS/FACE_TANGENTS/FACE_BITANGENTS in
+// voxel_face_tables.wgsl; used to walk a quad's corners and to
+// step outward across the face when sampling AO occluders.
+const FACE_NORMALS: [IVec3; 6] = [
+    IVec3::new(1, 0, 0),
+    IVec3::new(-1, 0, 0),
+    IVec3::new(0, 1, 0),
+    IVec3::new(0, -1, 0),
+    IVec3::new(0, 0, 1),
+    IVec3::new(0, 0, -1),
+];
+const FACE_TANGENTS: [IVec3; 6] = [
+    IVec3::new(0, 1, 0),
+    IVec3::new(0, 1, 0),
+    IVec3::new(0, 0, 1),
+    IVec3::new(0, 0, 1),
+    IVec3::new(1, 0, 0),
+    IVec3::new(1, 0, 0),
+];
+const FACE_BITANGENTS: [IVec3; 6] = [
+    IVec3::new(0, 0, 1),
+    IVec3::new(0, 0, 1),
+    IVec3::new(1, 0, 0),
+    IVec3::new(1, 0, 0),
+    IVec3::new(0, 1, 0),
+    IVec3::new(0, 1, 0),
+];
+
+// True if the voxel at `pos` (chunk-local, one layer of out-of-range
+// coordinates allowed on every side) is solid. Positions past that border
+// would need a neighbouring chunk's data, which chunks don't share yet, so
+// they're treated as empty; that under-occludes quads at a chunk boundary.
+fn voxel_is_solid(voxels: &[u16], pos: IVec3) -> bool {
+    let cs = CS as i32;
+    if pos.x < -1 || pos.y < -1 || pos.z < -1 || pos.x > cs || pos.y > cs || pos.z > cs {
+        return false;
+    }
+    // `pad_linearize` itself shifts its unpadded, chunk-local arguments into
+    // padded space, so reuse it directly whenever `pos` is non-negative on
+    // every axis (it already covers up to `CS` inclusive). The one case it
+    // can't take is a `-1` component, since that isn't representable as a
+    // `usize`; shift into the padded grid ourselves for that border layer,
+    // matching `pad_linearize`'s own z-fastest axis order.
+    if pos.x >= 0 && pos.y >= 0 && pos.z >= 0 {
+        voxels[pad_linearize(pos.x as usize, pos.y as usize, pos.z as usize)] != 0
+    } else {
+        let padded = pos + IVec3::ONE;
+        let index = padded.z as usize + padded.x as usize * CS_P + padded.y as usize * CS_P * CS_P;
+        voxels[index] != 0
+    }
+}
+
+// Classic Minecraft-style 0-3 ambient occlusion level for one corner of a
+// quad: 0 is fully occluded, 3 is unoccluded. `side1`/`side2` are the corner's
+// two edge-adjacent neighbours and `corner_voxel` the diagonal one; if both
+// edges are solid the corner is maximally occluded regardless of the
+// diagonal.
+fn corner_ao(voxels: &[u16], corner: IVec3, face: usize, tangent_sign: IVec3, bitangent_sign: IVec3) -> u8 {
+    // `corner` sits on the solid cell for negative faces (so the adjacent air
+    // cell is one more step along `normal`), but already sits on the air cell
+    // for positive faces (the mesher encodes those on the far side of the
+    // solid voxel) — see `mesh_chunk`'s source-voxel lookup.
+    let normal = FACE_NORMALS[face];
+    let base = if face % 2 == 0 { corner } else { corner + normal };
+    let side1 = voxel_is_solid(voxels, base + tangent_sign);
+    let side2 = voxel_is_solid(voxels, base + bitangent_sign);
+    let corner_voxel = voxel_is_solid(voxels, base + tangent_sign + bitangent_sign);
+    if side1 && side2 {
+        0
+    } else {
+        3 - (side1 as u8 + side2 as u8 + corner_voxel as u8)
+    }
+}
+
+// Computes the 4 corner AO levels for a quad and packs them 2 bits apiece, in
+// the same corner order as QUAD_CORNERS' 4 distinct corners in
+// tangent/bitangent space. Greedy-merged quads are sampled only at their own
+// 4 outer corners rather than per original cell, so a merged quad can show an
+// "AO bleed" gradient instead of breaking at cell boundaries; the mesher
+// doesn't expose per-cell merge gating, so that's left as-is.
+fn quad_ao(voxels: &[u16], decoded: &DecodedQuad, face: usize) -> u8 {
+    let tangent = FACE_TANGENTS[face];
+    let bitangent = FACE_BITANGENTS[face];
+    let origin = IVec3::new(decoded.x as i32, decoded.y as i32, decoded.z as i32);
+    // Each corner is given as the quad's own cell at that corner (the last
+    // cell is at `w - 1` / `h - 1`), so stepping by the signs reaches the
+    // voxels just outside the quad that touch the corner vertex.
+    let last_w = tangent * (decoded.w as i32 - 1);
+    let last_h = bitangent * (decoded.h as i32 - 1);
+
+    let corners = [
+        (origin, -tangent, -bitangent),
+        (origin + last_w, tangent, -bitangent),
+        (origin + last_w + last_h, tangent, bitangent),
+        (origin + last_h, -tangent, bitangent),
+    ];
+
+    let mut packed = 0u8;
+    for (i, (corner, tangent_sign, bitangent_sign)) in corners.into_iter().enumerate() {
+        let ao = corner_ao(voxels, corner, face, tangent_sign, bitangent_sign);
+        packed |= ao << (i * 2);
+    }
+    packed
+}
+
+fn mesh_chunk(voxels: &[u16], transparent_voxels: &BTreeSet<u16>) -> ChunkMesh {
     let mut mesher = Mesher::new();
-    let mut voxels = vec![0u16; CS_P3];
-    let transparent_voxels = BTreeSet::new();
-    voxels[pad_linearize(0, 0, 0)] = 1;
-    let opaque_mask = binary_greedy_meshing::compute_opaque_mask(&voxels, &transparent_voxels);
+    let opaque_mask = binary_greedy_meshing::compute_opaque_mask(voxels, transparent_voxels);
     let transparent_mask =
-        binary_greedy_meshing::compute_transparent_mask(&voxels, &transparent_voxels);
-    mesher.fast_mesh(&voxels, &opaque_mask, &transparent_mask);
-
-    // Generate encoded quads
-    mesher
-        .quads
-        .into_iter()
-        .enumerate()
-        .flat_map(|(face, quads)| {
-            let face = (face as u64) << 61;
-            quads.into_iter().map(move |quad| face | quad)
-        })
-        // Flatten u64 -> [u32; 2] (lo, hi)
-        .map(|quad| InstanceData {
-            low: quad as u32,
-            high: (quad >> 32) as u32,
-        })
-        .collect::<Vec<_>>()
+        binary_greedy_meshing::compute_transparent_mask(voxels, transparent_voxels);
+    mesher.fast_mesh(voxels, &opaque_mask, &transparent_mask);
+
+    let mut opaque = Vec::new();
+    let mut transparent = Vec::new();
+
+    for (face, quads) in mesher.quads.into_iter().enumerate() {
+        let face_bits = (face as u64) << 61;
+        let normal = FACE_NORMALS[face];
+        for quad in quads {
+            let decoded = decode_quad(quad);
+            // Positive faces encode the plane coordinate one voxel past the
+            // solid cell (`solid + normal`); step back so this reads the
+            // source voxel, not its neighbour.
+            let source = if face % 2 == 0 {
+                IVec3::new(decoded.x as i32, decoded.y as i32, decoded.z as i32) - normal
+            } else {
+                IVec3::new(decoded.x as i32, decoded.y as i32, decoded.z as i32)
+            };
+            let voxel =
+                voxels[pad_linearize(source.x as usize, source.y as usize, source.z as usize)];
+            let ao = quad_ao(voxels, &decoded, face);
+            // Bits 32..48 of the quad are the crate's own voxel-value field
+            // (and bits 61..64 are `face_bits`), so pack AO into the bits
+            // between them instead of colliding with the value.
+            let encoded = face_bits | quad | ((ao as u64) << 48);
+            let instance = InstanceData {
+                low: encoded as u32,
+                high: (encoded >> 32) as u32,
+            };
+            if transparent_voxels.contains(&voxel) {
+                transparent.push(instance);
+            } else {
+                opaque.push(instance);
+            }
+        }
+    }
+
+    ChunkMesh { opaque, transparent }
 }
 
-// ---------- Systems ----------
+/// The world-space origin of a chunk, in voxels. The mesher encodes only
+/// chunk-local coordinates into each quad, so the shader adds this back in.
+#[derive(Component, Clone, Copy)]
+struct ChunkOrigin(Vec3);
+
+impl ExtractComponent for ChunkOrigin {
+    type QueryData = &'static ChunkOrigin;
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component(item: QueryItem<'_, Self::QueryData>) -> Option<Self> {
+        Some(*item)
+    }
+}
+
+// The single degenerate placeholder mesh every chunk entity is drawn with;
+// real quad geometry comes entirely from instance data, so every chunk can
+// share one mesh asset.
+#[derive(Resource, Clone)]
+struct ChunkMeshHandle(Handle<Mesh>);
+
+impl ExtractResource for ChunkMeshHandle {
+    type Source = ChunkMeshHandle;
+
+    fn extract_resource(source: &Self::Source) -> Self {
+        source.clone()
+    }
+}
+
+// Toggles the debug overlay drawing each meshed quad's outward normal (see
+// `DrawQuadDebugLines`), the same way `WireframeConfig` toggles wireframes.
+#[derive(Resource, Clone, Copy)]
+struct QuadDebugConfig {
+    global: bool,
+}
 
-fn setup(
+impl ExtractResource for QuadDebugConfig {
+    type Source = QuadDebugConfig;
+
+    fn extract_resource(source: &Self::Source) -> Self {
+        *source
+    }
+}
+
+// Meshes every newly spawned `VoxelChunk`. Later edits to an already-spawned
+// chunk are instead picked up by `remesh_dirty_chunks`.
+fn mesh_new_chunks(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
+    chunk_mesh: Res<ChunkMeshHandle>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut chunks: Query<(Entity, &mut VoxelChunk), Added<VoxelChunk>>,
 ) {
-    let mut transform = Transform::from_xyz(-2., 0., 0.).with_scale(Vec3::splat(0.5));
-    transform.rotate_y(TAU * 0.5);
-    transform.rotate_z(TAU * 0.5);
-    meshes.add(Rectangle::new(1.0, 1.0));
+    for (entity, mut chunk) in &mut chunks {
+        // World position is carried entirely by `ChunkOrigin`, not `Transform`,
+        // so the GPU-driven batching in a later pass can drop per-chunk
+        // transforms altogether.
+        let origin = (chunk.coord * CS as i32).as_vec3();
+        let meshed = mesh_chunk(&chunk.voxels, &chunk.transparent_voxels);
+        chunk.dirty = false;
+        commands.entity(entity).insert((
+            Transform::IDENTITY,
+            Mesh3d(chunk_mesh.0.clone()),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                metallic: 0.0,
+                ..Default::default()
+            })),
+            InstanceMaterialData(meshed.opaque),
+            TransparentInstanceData(meshed.transparent),
+            ChunkOrigin(origin),
+            // // NOTE: Frustum culling is done based on the Aabb of the Mesh and the GlobalTransform.
+            // // As the cube is at the origin, if its Aabb moves outside the view frustum, all the
+            // // instanced cubes will be culled.
+            // // The InstanceMaterialData contains the 'GlobalTransform' information for this custom
+            // // instancing, and that is not taken into account with the built-in frustum culling.
+            // // We must disable the built-in frustum culling by adding the `NoFrustumCulling` marker
+            // // component to avoid incorrect culling.
+            NoFrustumCulling,
+        ));
+    }
+}
 
-    commands.spawn((
-        Rotate,
-        Transform::from_xyz(-2., 0., 0.).with_scale(Vec3::new(0.5, 0.5, 1.0)),
-        Mesh3d(meshes.add(Rectangle::new(0.0, 0.0))),
-        MeshMaterial3d(materials.add(StandardMaterial {
-            metallic: 0.0,
-            ..Default::default()
-        })),
-        InstanceMaterialData(quads()),
-        // // NOTE: Frustum culling is done based on the Aabb of the Mesh and the GlobalTransform.
-        // // As the cube is at the origin, if its Aabb moves outside the view frustum, all the
-        // // instanced cubes will be culled.
-        // // The InstanceMaterialData contains the 'GlobalTransform' information for this custom
-        // // instancing, and that is not taken into account with the built-in frustum culling.
-        // // We must disable the built-in frustum culling by adding the `NoFrustumCulling` marker
-        // // component to avoid incorrect culling.
-        NoFrustumCulling,
-    ));
-    commands.spawn((
-        Rotate,
-        Transform::from_xyz(2., 0., 0.).with_scale(Vec3::new(1.0, 0.5, 0.5)),
-        Mesh3d(meshes.add(Rectangle::new(0.0, 0.0))),
-        MeshMaterial3d(materials.add(StandardMaterial {
-            metallic: 1.0,
-            ..Default::default()
-        })),
-        InstanceMaterialData(quads()),
-        // // NOTE: Frustum culling is done based on the Aabb of the Mesh and the GlobalTransform.
-        // // As the cube is at the origin, if its Aabb moves outside the view frustum, all the
-        // // instanced cubes will be culled.
-        // // The InstanceMaterialData contains the 'GlobalTransform' information for this custom
-        // // instancing, and that is not taken into account with the built-in frustum culling.
-        // // We must disable the built-in frustum culling by adding the `NoFrustumCulling` marker
-        // // component to avoid incorrect culling.
-        NoFrustumCulling,
-    ));
+// Re-meshes every `VoxelChunk` whose `dirty` flag is set, leaving every other
+// chunk's instance data untouched.
+fn remesh_dirty_chunks(
+    mut chunks: Query<(&mut VoxelChunk, &mut InstanceMaterialData, &mut TransparentInstanceData)>,
+) {
+    for (mut chunk, mut instance_data, mut transparent_data) in &mut chunks {
+        if !chunk.dirty {
+            continue;
+        }
+        let mesh = mesh_chunk(&chunk.voxels, &chunk.transparent_voxels);
+        instance_data.0 = mesh.opaque;
+        transparent_data.0 = mesh.transparent;
+        chunk.dirty = false;
+    }
+}
+
+// ---------- Systems ----------
+
+fn setup(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+    commands.insert_resource(ChunkMeshHandle(meshes.add(Rectangle::new(0.0, 0.0))));
+
+    // A small 2x2 grid of chunks, each with a single voxel set near its
+    // origin, to exercise multi-chunk streaming.
+    for cx in 0..2 {
+        for cz in 0..2 {
+            let coord = IVec3::new(cx, 0, cz);
+            let mut chunk = VoxelChunk::empty(coord);
+            chunk.set_voxel(0, 0, 0, 1);
+            let mut entity = commands.spawn(chunk);
+            if (cx + cz) % 2 == 0 {
+                entity.insert(Rotate);
+            }
+        }
+    }
 
     // light
     commands.spawn((
@@ -255,25 +546,54 @@ impl ExtractComponent for InstanceMaterialData {
     }
 }
 
+// The transparent counterpart of `InstanceMaterialData`, kept as its own
+// component so `ExtractComponentPlugin` can extract it independently.
+#[derive(Component, Deref)]
+struct TransparentInstanceData(Vec<InstanceData>);
+
+impl ExtractComponent for TransparentInstanceData {
+    type QueryData = &'static TransparentInstanceData;
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component(item: QueryItem<'_, Self::QueryData>) -> Option<Self> {
+        Some(TransparentInstanceData(item.0.clone()))
+    }
+}
+
 struct CustomMaterialPlugin;
 
 impl Plugin for CustomMaterialPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ExtractComponentPlugin::<InstanceMaterialData>::default());
+        app.add_plugins((
+            ExtractComponentPlugin::<InstanceMaterialData>::default(),
+            ExtractComponentPlugin::<TransparentInstanceData>::default(),
+            ExtractComponentPlugin::<ChunkOrigin>::default(),
+            ExtractResourcePlugin::<ChunkMeshHandle>::default(),
+            ExtractResourcePlugin::<QuadDebugConfig>::default(),
+        ));
         app.sub_app_mut(RenderApp)
-            .add_render_command::<Transparent3d, DrawCustom>()
+            .add_render_command::<Opaque3d, DrawCustomOpaque>()
+            .add_render_command::<Transparent3d, DrawCustomTransparent>()
+            .add_render_command::<Opaque3d, DrawQuadDebugNormals>()
             .init_resource::<SpecializedMeshPipelines<CustomPipeline>>()
+            .init_resource::<SpecializedMeshPipelines<QuadDebugPipeline>>()
             .add_systems(
                 Render,
                 (
-                    queue_custom.in_set(RenderSet::QueueMeshes),
-                    prepare_instance_buffers.in_set(RenderSet::PrepareResources),
+                    queue_opaque_chunks.in_set(RenderSet::QueueMeshes),
+                    queue_transparent_chunks.in_set(RenderSet::QueueMeshes),
+                    queue_quad_debug_lines.in_set(RenderSet::QueueMeshes),
+                    prepare_chunk_batch.in_set(RenderSet::PrepareResources),
+                    prepare_transparent_chunk_batch.in_set(RenderSet::PrepareResources),
                 ),
             );
     }
 
     fn finish(&self, app: &mut App) {
-        app.sub_app_mut(RenderApp).init_resource::<CustomPipeline>();
+        app.sub_app_mut(RenderApp)
+            .init_resource::<CustomPipeline>()
+            .init_resource::<QuadDebugPipeline>();
     }
 }
 
@@ -284,18 +604,154 @@ struct InstanceData {
     high: u32,
 }
 
-fn queue_custom(
-    transparent_3d_draw_functions: Res<DrawFunctions<Transparent3d>>,
+// Queues a single `Opaque3d` bin per view that draws every opaque chunk in
+// one indirect multi-draw call (see `DrawGpuChunkBatch`), rather than one
+// item per chunk entity.
+fn queue_opaque_chunks(
+    opaque_3d_draw_functions: Res<DrawFunctions<Opaque3d>>,
     custom_pipeline: Res<CustomPipeline>,
     mut pipelines: ResMut<SpecializedMeshPipelines<CustomPipeline>>,
     pipeline_cache: Res<PipelineCache>,
     meshes: Res<RenderAssets<RenderMesh>>,
     render_mesh_instances: Res<RenderMeshInstances>,
     material_meshes: Query<(Entity, &MainEntity), With<InstanceMaterialData>>,
+    mut opaque_render_phases: ResMut<ViewBinnedRenderPhases<Opaque3d>>,
+    views: Query<(&ExtractedView, &Msaa)>,
+) {
+    let draw_opaque = opaque_3d_draw_functions.read().id::<DrawCustomOpaque>();
+
+    let Some((representative_entity, representative_main_entity)) =
+        material_meshes.iter().next()
+    else {
+        return;
+    };
+
+    for (view, msaa) in &views {
+        let Some(opaque_phase) = opaque_render_phases.get_mut(&view.retained_view_entity) else {
+            continue;
+        };
+
+        let msaa_key = MeshPipelineKey::from_msaa_samples(msaa.samples());
+        let view_key = msaa_key | MeshPipelineKey::from_hdr(view.hdr);
+        let Some(mesh_instance) =
+            render_mesh_instances.render_mesh_queue_data(*representative_main_entity)
+        else {
+            continue;
+        };
+        let Some(mesh) = meshes.get(mesh_instance.mesh_asset_id) else {
+            continue;
+        };
+        let key = CustomPipelineKey {
+            mesh_key: view_key | MeshPipelineKey::from_primitive_topology(mesh.primitive_topology()),
+            transparent: false,
+        };
+        let pipeline = pipelines
+            .specialize(&pipeline_cache, &custom_pipeline, key, &mesh.layout)
+            .unwrap();
+        opaque_phase.add(
+            Opaque3dBinKey {
+                draw_function: draw_opaque,
+                pipeline,
+                asset_id: mesh_instance.mesh_asset_id.into(),
+                material_bind_group_index: None,
+                lightmap_slab_index: None,
+            },
+            (representative_entity, *representative_main_entity),
+            BinnedRenderPhaseType::UnbatchableMesh,
+        );
+    }
+}
+
+// Queues a second `Opaque3d` bin alongside `queue_opaque_chunks`'s, drawing
+// `GpuChunkBatch`'s quads as short debug normal lines instead of real
+// geometry, when `QuadDebugConfig.global` is enabled.
+fn queue_quad_debug_lines(
+    config: Res<QuadDebugConfig>,
+    opaque_3d_draw_functions: Res<DrawFunctions<Opaque3d>>,
+    debug_pipeline: Res<QuadDebugPipeline>,
+    mut pipelines: ResMut<SpecializedMeshPipelines<QuadDebugPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    meshes: Res<RenderAssets<RenderMesh>>,
+    render_mesh_instances: Res<RenderMeshInstances>,
+    material_meshes: Query<(Entity, &MainEntity), With<InstanceMaterialData>>,
+    mut opaque_render_phases: ResMut<ViewBinnedRenderPhases<Opaque3d>>,
+    views: Query<(&ExtractedView, &Msaa)>,
+) {
+    if !config.global {
+        return;
+    }
+
+    let draw_debug = opaque_3d_draw_functions.read().id::<DrawQuadDebugNormals>();
+
+    let Some((representative_entity, representative_main_entity)) =
+        material_meshes.iter().next()
+    else {
+        return;
+    };
+
+    for (view, msaa) in &views {
+        let Some(opaque_phase) = opaque_render_phases.get_mut(&view.retained_view_entity) else {
+            continue;
+        };
+
+        let msaa_key = MeshPipelineKey::from_msaa_samples(msaa.samples());
+        let view_key = msaa_key | MeshPipelineKey::from_hdr(view.hdr);
+        let Some(mesh_instance) =
+            render_mesh_instances.render_mesh_queue_data(*representative_main_entity)
+        else {
+            continue;
+        };
+        let Some(mesh) = meshes.get(mesh_instance.mesh_asset_id) else {
+            continue;
+        };
+        let key = view_key | MeshPipelineKey::from_primitive_topology(PrimitiveTopology::LineList);
+        let pipeline = pipelines
+            .specialize(&pipeline_cache, &debug_pipeline, key, &mesh.layout)
+            .unwrap();
+        opaque_phase.add(
+            Opaque3dBinKey {
+                draw_function: draw_debug,
+                pipeline,
+                asset_id: mesh_instance.mesh_asset_id.into(),
+                material_bind_group_index: None,
+                lightmap_slab_index: None,
+            },
+            (representative_entity, *representative_main_entity),
+            BinnedRenderPhaseType::UnbatchableMesh,
+        );
+    }
+}
+
+// Queues one `Transparent3d` item per transparent quad, each reading a single
+// instance out of `TransparentChunkBatch` via `PhaseItemExtraIndex`'s dynamic
+// offset. Unlike the opaque batch these aren't merged into one draw, since
+// `Transparent3d` sorts its items back-to-front per quad.
+fn queue_transparent_chunks(
+    transparent_3d_draw_functions: Res<DrawFunctions<Transparent3d>>,
+    custom_pipeline: Res<CustomPipeline>,
+    mut pipelines: ResMut<SpecializedMeshPipelines<CustomPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    meshes: Res<RenderAssets<RenderMesh>>,
+    render_mesh_instances: Res<RenderMeshInstances>,
+    material_meshes: Query<(Entity, &MainEntity), With<TransparentInstanceData>>,
+    batch: Option<Res<TransparentChunkBatch>>,
     mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent3d>>,
     views: Query<(&ExtractedView, &Msaa)>,
 ) {
-    let draw_custom = transparent_3d_draw_functions.read().id::<DrawCustom>();
+    let Some(batch) = batch else {
+        return;
+    };
+    if batch.quads.is_empty() {
+        return;
+    }
+
+    let Some((representative_entity, representative_main_entity)) =
+        material_meshes.iter().next()
+    else {
+        return;
+    };
+
+    let draw_transparent = transparent_3d_draw_functions.read().id::<DrawCustomTransparent>();
 
     for (view, msaa) in &views {
         let Some(transparent_phase) = transparent_render_phases.get_mut(&view.retained_view_entity)
@@ -304,57 +760,305 @@ fn queue_custom(
         };
 
         let msaa_key = MeshPipelineKey::from_msaa_samples(msaa.samples());
-
         let view_key = msaa_key | MeshPipelineKey::from_hdr(view.hdr);
+        let Some(mesh_instance) =
+            render_mesh_instances.render_mesh_queue_data(*representative_main_entity)
+        else {
+            continue;
+        };
+        let Some(mesh) = meshes.get(mesh_instance.mesh_asset_id) else {
+            continue;
+        };
+        let key = CustomPipelineKey {
+            mesh_key: view_key | MeshPipelineKey::from_primitive_topology(mesh.primitive_topology()),
+            transparent: true,
+        };
+        let pipeline = pipelines
+            .specialize(&pipeline_cache, &custom_pipeline, key, &mesh.layout)
+            .unwrap();
         let rangefinder = view.rangefinder3d();
-        for (entity, main_entity) in &material_meshes {
-            let Some(mesh_instance) = render_mesh_instances.render_mesh_queue_data(*main_entity)
-            else {
-                continue;
-            };
-            let Some(mesh) = meshes.get(mesh_instance.mesh_asset_id) else {
-                continue;
-            };
-            let key =
-                view_key | MeshPipelineKey::from_primitive_topology(mesh.primitive_topology());
-            let pipeline = pipelines
-                .specialize(&pipeline_cache, &custom_pipeline, key, &mesh.layout)
-                .unwrap();
-            // info!(%entity, "queing");
+
+        for quad in &batch.quads {
             transparent_phase.add(Transparent3d {
-                entity: (entity, *main_entity),
+                entity: (representative_entity, *representative_main_entity),
                 pipeline,
-                draw_function: draw_custom,
-                distance: rangefinder.distance_translation(&mesh_instance.translation),
+                draw_function: draw_transparent,
+                distance: rangefinder.distance_translation(&quad.world_pos),
                 batch_range: 0..1,
-                extra_index: PhaseItemExtraIndex::None,
+                extra_index: PhaseItemExtraIndex::DynamicOffset(quad.instance_index),
                 indexed: true,
             });
         }
     }
 }
 
-#[derive(Component)]
-struct InstanceBuffer {
+/// Arguments for one `multi_draw_indexed_indirect` entry, laid out to match
+/// the GPU's indexed-indirect-draw command (`index_count`, `instance_count`,
+/// `first_index`, `base_vertex`, `first_instance`).
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+struct DrawIndexedIndirectArgs {
+    index_count: u32,
+    instance_count: u32,
+    first_index: u32,
+    base_vertex: i32,
+    first_instance: u32,
+}
+
+// A GPU buffer alongside the byte capacity it was last allocated with, so
+// `write_or_grow_buffer` can tell whether new data fits in place.
+struct SizedBuffer {
     buffer: Buffer,
-    length: usize,
+    capacity: u64,
 }
 
-fn prepare_instance_buffers(
+// Uploads `data` into `sized.buffer`, reusing it in place via
+// `RenderQueue::write_buffer` when it fits within the current capacity, and
+// only reallocating via `create_buffer_with_data` once it's grown past that.
+fn write_or_grow_buffer(
+    render_device: &RenderDevice,
+    render_queue: &RenderQueue,
+    sized: &mut SizedBuffer,
+    label: &'static str,
+    usage: BufferUsages,
+    data: &[u8],
+) {
+    if data.len() as u64 <= sized.capacity {
+        render_queue.write_buffer(&sized.buffer, 0, data);
+    } else {
+        sized.buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
+            label: Some(label),
+            contents: data,
+            usage,
+        });
+        sized.capacity = data.len() as u64;
+    }
+}
+
+// Every chunk's `InstanceData`, concatenated into one shared vertex buffer
+// alongside a parallel per-instance chunk-origin buffer, plus one indirect
+// draw-args entry per chunk. Buffers are reused across frames via
+// `write_or_grow_buffer`.
+#[derive(Resource)]
+struct GpuChunkBatch {
+    instances: SizedBuffer,
+    origins: SizedBuffer,
+    indirect_args: SizedBuffer,
+    draw_count: u32,
+    // Total instance count across every chunk. `DrawGpuChunkBatch` doesn't
+    // need this (the indirect args carry each chunk's own count), but
+    // `DrawQuadDebugLines` draws the whole buffer in one non-indirect call.
+    total_instances: u32,
+}
+
+// Concatenates every chunk's instance data into one shared vertex buffer and
+// builds the matching indirect-draw-args buffer, so `DrawGpuChunkBatch` can
+// render the whole world with a single `multi_draw_indexed_indirect` call.
+fn prepare_chunk_batch(
     mut commands: Commands,
-    query: Query<(Entity, &InstanceMaterialData)>,
+    chunks: Query<(&InstanceMaterialData, &ChunkOrigin)>,
+    chunk_mesh: Res<ChunkMeshHandle>,
+    meshes: Res<RenderAssets<RenderMesh>>,
+    mesh_allocator: Res<MeshAllocator>,
     render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    existing_batch: Option<ResMut<GpuChunkBatch>>,
 ) {
-    // info!("preparing");
-    for (entity, instance_data) in &query {
-        let buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
-            label: Some("instance data buffer"),
-            contents: bytemuck::cast_slice(instance_data.as_slice()),
-            usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+    let Some(gpu_mesh) = meshes.get(chunk_mesh.0.id()) else {
+        return;
+    };
+    let RenderMeshBufferInfo::Indexed { count, .. } = gpu_mesh.buffer_info else {
+        return;
+    };
+    // The placeholder mesh shares the allocator's big vertex/index buffers
+    // with every other mesh in the world, so its geometry can sit at a
+    // nonzero offset; carry that offset into each indirect-draw command the
+    // same way the non-batched draws apply it to their own `draw_indexed`.
+    let Some(vertex_buffer_slice) = mesh_allocator.mesh_vertex_slice(&chunk_mesh.0.id()) else {
+        return;
+    };
+    let Some(index_buffer_slice) = mesh_allocator.mesh_index_slice(&chunk_mesh.0.id()) else {
+        return;
+    };
+
+    let mut instances = Vec::new();
+    let mut origins = Vec::new();
+    let mut indirect_args = Vec::new();
+    let mut first_instance = 0u32;
+
+    for (instance_data, origin) in &chunks {
+        let instance_count = instance_data.len() as u32;
+        if instance_count == 0 {
+            continue;
+        }
+        instances.extend_from_slice(instance_data.as_slice());
+        origins.extend(std::iter::repeat_n(origin.0, instance_count as usize));
+        indirect_args.push(DrawIndexedIndirectArgs {
+            index_count: count,
+            instance_count,
+            first_index: index_buffer_slice.range.start,
+            base_vertex: vertex_buffer_slice.range.start as i32,
+            first_instance,
+        });
+        first_instance += instance_count;
+    }
+
+    if indirect_args.is_empty() {
+        return;
+    }
+
+    let instances_bytes: &[u8] = bytemuck::cast_slice(&instances);
+    let origins_bytes: &[u8] = bytemuck::cast_slice(&origins);
+    let indirect_bytes: &[u8] = bytemuck::cast_slice(&indirect_args);
+
+    if let Some(mut batch) = existing_batch {
+        write_or_grow_buffer(
+            &render_device,
+            &render_queue,
+            &mut batch.instances,
+            "chunk batch instance buffer",
+            BufferUsages::VERTEX | BufferUsages::COPY_DST,
+            instances_bytes,
+        );
+        write_or_grow_buffer(
+            &render_device,
+            &render_queue,
+            &mut batch.origins,
+            "chunk batch origin buffer",
+            BufferUsages::VERTEX | BufferUsages::COPY_DST,
+            origins_bytes,
+        );
+        write_or_grow_buffer(
+            &render_device,
+            &render_queue,
+            &mut batch.indirect_args,
+            "chunk batch indirect args buffer",
+            BufferUsages::INDIRECT | BufferUsages::COPY_DST,
+            indirect_bytes,
+        );
+        batch.draw_count = indirect_args.len() as u32;
+        batch.total_instances = first_instance;
+    } else {
+        commands.insert_resource(GpuChunkBatch {
+            instances: SizedBuffer {
+                buffer: render_device.create_buffer_with_data(&BufferInitDescriptor {
+                    label: Some("chunk batch instance buffer"),
+                    contents: instances_bytes,
+                    usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+                }),
+                capacity: instances_bytes.len() as u64,
+            },
+            origins: SizedBuffer {
+                buffer: render_device.create_buffer_with_data(&BufferInitDescriptor {
+                    label: Some("chunk batch origin buffer"),
+                    contents: origins_bytes,
+                    usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+                }),
+                capacity: origins_bytes.len() as u64,
+            },
+            indirect_args: SizedBuffer {
+                buffer: render_device.create_buffer_with_data(&BufferInitDescriptor {
+                    label: Some("chunk batch indirect args buffer"),
+                    contents: indirect_bytes,
+                    usage: BufferUsages::INDIRECT | BufferUsages::COPY_DST,
+                }),
+                capacity: indirect_bytes.len() as u64,
+            },
+            draw_count: indirect_args.len() as u32,
+            total_instances: first_instance,
         });
-        commands.entity(entity).insert(InstanceBuffer {
-            buffer,
-            length: instance_data.len(),
+    }
+}
+
+// One transparent quad's world position (for per-quad distance sort) and its
+// offset into `TransparentChunkBatch`'s instance/origin buffers.
+struct TransparentQuad {
+    world_pos: Vec3,
+    instance_index: u32,
+}
+
+// Every chunk's transparent `InstanceData`, concatenated the same way as
+// `GpuChunkBatch` but drawn one quad per `Transparent3d` item (see
+// `queue_transparent_chunks`). `quads` is plain CPU data, replaced each frame.
+#[derive(Resource)]
+struct TransparentChunkBatch {
+    instances: SizedBuffer,
+    origins: SizedBuffer,
+    quads: Vec<TransparentQuad>,
+}
+
+fn prepare_transparent_chunk_batch(
+    mut commands: Commands,
+    chunks: Query<(&TransparentInstanceData, &ChunkOrigin)>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    existing_batch: Option<ResMut<TransparentChunkBatch>>,
+) {
+    let mut instances = Vec::new();
+    let mut origins = Vec::new();
+    let mut quads = Vec::new();
+
+    for (instance_data, origin) in &chunks {
+        for instance in instance_data.iter() {
+            let quad = ((instance.high as u64) << 32) | instance.low as u64;
+            let decoded = decode_quad(quad);
+            quads.push(TransparentQuad {
+                world_pos: origin.0
+                    + Vec3::new(decoded.x as f32, decoded.y as f32, decoded.z as f32),
+                instance_index: instances.len() as u32,
+            });
+            instances.push(*instance);
+            origins.push(origin.0);
+        }
+    }
+
+    if quads.is_empty() {
+        if let Some(mut batch) = existing_batch {
+            batch.quads.clear();
+        }
+        return;
+    }
+
+    let instances_bytes: &[u8] = bytemuck::cast_slice(&instances);
+    let origins_bytes: &[u8] = bytemuck::cast_slice(&origins);
+
+    if let Some(mut batch) = existing_batch {
+        write_or_grow_buffer(
+            &render_device,
+            &render_queue,
+            &mut batch.instances,
+            "transparent chunk instance buffer",
+            BufferUsages::VERTEX | BufferUsages::COPY_DST,
+            instances_bytes,
+        );
+        write_or_grow_buffer(
+            &render_device,
+            &render_queue,
+            &mut batch.origins,
+            "transparent chunk origin buffer",
+            BufferUsages::VERTEX | BufferUsages::COPY_DST,
+            origins_bytes,
+        );
+        batch.quads = quads;
+    } else {
+        commands.insert_resource(TransparentChunkBatch {
+            instances: SizedBuffer {
+                buffer: render_device.create_buffer_with_data(&BufferInitDescriptor {
+                    label: Some("transparent chunk instance buffer"),
+                    contents: instances_bytes,
+                    usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+                }),
+                capacity: instances_bytes.len() as u64,
+            },
+            origins: SizedBuffer {
+                buffer: render_device.create_buffer_with_data(&BufferInitDescriptor {
+                    label: Some("transparent chunk origin buffer"),
+                    contents: origins_bytes,
+                    usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+                }),
+                capacity: origins_bytes.len() as u64,
+            },
+            quads,
         });
     }
 }
@@ -382,15 +1086,24 @@ impl FromWorld for CustomPipeline {
     }
 }
 
+/// `CustomPipeline`'s specialization key: the usual mesh pipeline key, plus
+/// whether this variant draws the transparent (alpha-blended) quad stream or
+/// the opaque one.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct CustomPipelineKey {
+    mesh_key: MeshPipelineKey,
+    transparent: bool,
+}
+
 impl SpecializedMeshPipeline for CustomPipeline {
-    type Key = MeshPipelineKey;
+    type Key = CustomPipelineKey;
 
     fn specialize(
         &self,
         key: Self::Key,
         layout: &MeshVertexBufferLayoutRef,
     ) -> Result<RenderPipelineDescriptor, SpecializedMeshPipelineError> {
-        let mut descriptor = self.mesh_pipeline.specialize(key, layout)?;
+        let mut descriptor = self.mesh_pipeline.specialize(key.mesh_key, layout)?;
 
         descriptor.vertex.shader_defs.push("BINDLESS".into());
         descriptor.vertex.shader_defs.push("VERTEX_COLORS".into());
@@ -404,11 +1117,28 @@ impl SpecializedMeshPipeline for CustomPipeline {
                 shader_location: 3, // shader locations 0-2 are taken up by Position, Normal and UV attributes
             }],
         });
+        // Per-instance chunk origin, parallel to the instance data buffer
+        // above; `prepare_chunk_batch` keeps both buffers in lockstep so the
+        // same instance index indexes into either.
+        descriptor.vertex.buffers.push(VertexBufferLayout {
+            array_stride: size_of::<Vec3>() as u64,
+            step_mode: VertexStepMode::Instance,
+            attributes: vec![VertexAttribute {
+                format: VertexFormat::Float32x3,
+                offset: 0,
+                shader_location: 4,
+            }],
+        });
 
         let fragment = descriptor.fragment.as_mut().unwrap();
         fragment.shader_defs.push("BINDLESS".into());
         fragment.shader_defs.push("VERTEX_COLORS".into());
         fragment.shader = self.shader.clone();
+        fragment.targets[0].as_mut().unwrap().blend = if key.transparent {
+            Some(BlendState::ALPHA_BLENDING)
+        } else {
+            None
+        };
 
         assert_eq!(2, descriptor.layout.len());
         descriptor.layout.push(self.material_layout.clone());
@@ -417,35 +1147,115 @@ impl SpecializedMeshPipeline for CustomPipeline {
     }
 }
 
-type DrawCustom = (
+// A second, smaller pipeline for the `QuadDebugConfig` overlay: reuses the
+// mesh pipeline and instance/origin vertex buffers, but draws
+// `voxel_debug_normals.wgsl`'s line-list geometry and needs no material bind
+// group since the overlay is a flat, unlit color.
+#[derive(Resource)]
+struct QuadDebugPipeline {
+    shader: Handle<Shader>,
+    mesh_pipeline: MeshPipeline,
+}
+
+impl FromWorld for QuadDebugPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let mesh_pipeline = world.resource::<MeshPipeline>();
+
+        QuadDebugPipeline {
+            shader: world.load_asset("shaders/voxel_debug_normals.wgsl"),
+            mesh_pipeline: mesh_pipeline.clone(),
+        }
+    }
+}
+
+impl SpecializedMeshPipeline for QuadDebugPipeline {
+    type Key = MeshPipelineKey;
+
+    fn specialize(
+        &self,
+        key: Self::Key,
+        layout: &MeshVertexBufferLayoutRef,
+    ) -> Result<RenderPipelineDescriptor, SpecializedMeshPipelineError> {
+        let mut descriptor = self.mesh_pipeline.specialize(key, layout)?;
+
+        descriptor.vertex.shader = self.shader.clone();
+        descriptor.vertex.buffers.push(VertexBufferLayout {
+            array_stride: size_of::<InstanceData>() as u64,
+            step_mode: VertexStepMode::Instance,
+            attributes: vec![VertexAttribute {
+                format: VertexFormat::Uint32x2,
+                offset: 0,
+                shader_location: 3,
+            }],
+        });
+        descriptor.vertex.buffers.push(VertexBufferLayout {
+            array_stride: size_of::<Vec3>() as u64,
+            step_mode: VertexStepMode::Instance,
+            attributes: vec![VertexAttribute {
+                format: VertexFormat::Float32x3,
+                offset: 0,
+                shader_location: 4,
+            }],
+        });
+        descriptor.primitive.topology = PrimitiveTopology::LineList;
+
+        let fragment = descriptor.fragment.as_mut().unwrap();
+        fragment.shader = self.shader.clone();
+
+        assert_eq!(2, descriptor.layout.len());
+
+        Ok(descriptor)
+    }
+}
+
+type DrawCustomOpaque = (
     SetItemPipeline,
     SetMeshViewBindGroup<0>,
     SetMeshBindGroup<1>,
     SetMaterialBindGroup<StandardMaterial, 2>,
-    DrawMeshInstanced,
+    DrawGpuChunkBatch,
 );
 
-struct DrawMeshInstanced;
+type DrawCustomTransparent = (
+    SetItemPipeline,
+    SetMeshViewBindGroup<0>,
+    SetMeshBindGroup<1>,
+    SetMaterialBindGroup<StandardMaterial, 2>,
+    DrawTransparentChunkQuad,
+);
+
+type DrawQuadDebugNormals = (
+    SetItemPipeline,
+    SetMeshViewBindGroup<0>,
+    SetMeshBindGroup<1>,
+    DrawQuadDebugLines,
+);
+
+// Draws every chunk in `GpuChunkBatch` with a single
+// `multi_draw_indexed_indirect` call, replacing the old one-`draw_indexed`-
+// per-entity loop. Requires `WgpuFeatures::MULTI_DRAW_INDIRECT` and, since
+// `first_instance` is non-zero per chunk, `INDIRECT_FIRST_INSTANCE`.
+struct DrawGpuChunkBatch;
 
-impl<P: PhaseItem> RenderCommand<P> for DrawMeshInstanced {
+impl<P: PhaseItem> RenderCommand<P> for DrawGpuChunkBatch {
     type Param = (
         SRes<RenderAssets<RenderMesh>>,
         SRes<RenderMeshInstances>,
         SRes<MeshAllocator>,
+        SRes<GpuChunkBatch>,
     );
     type ViewQuery = ();
-    type ItemQuery = Read<InstanceBuffer>;
+    type ItemQuery = ();
 
     #[inline]
     fn render<'w>(
         item: &P,
         _view: (),
-        instance_buffer: Option<&'w InstanceBuffer>,
-        (meshes, render_mesh_instances, mesh_allocator): SystemParamItem<'w, '_, Self::Param>,
+        _item_query: Option<()>,
+        (meshes, render_mesh_instances, mesh_allocator, batch): SystemParamItem<'w, '_, Self::Param>,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
-        // info!(entity = %item.entity(),"Draw command");
-        // A borrow check workaround.
+        let batch = batch.into_inner();
         let mesh_allocator = mesh_allocator.into_inner();
 
         let Some(mesh_instance) = render_mesh_instances.render_mesh_queue_data(item.main_entity())
@@ -455,7 +1265,64 @@ impl<P: PhaseItem> RenderCommand<P> for DrawMeshInstanced {
         let Some(gpu_mesh) = meshes.into_inner().get(mesh_instance.mesh_asset_id) else {
             return RenderCommandResult::Skip;
         };
-        let Some(instance_buffer) = instance_buffer else {
+        let Some(vertex_buffer_slice) =
+            mesh_allocator.mesh_vertex_slice(&mesh_instance.mesh_asset_id)
+        else {
+            return RenderCommandResult::Skip;
+        };
+        let RenderMeshBufferInfo::Indexed { index_format, .. } = &gpu_mesh.buffer_info else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(index_buffer_slice) =
+            mesh_allocator.mesh_index_slice(&mesh_instance.mesh_asset_id)
+        else {
+            return RenderCommandResult::Skip;
+        };
+
+        pass.set_vertex_buffer(0, vertex_buffer_slice.buffer.slice(..));
+        pass.set_vertex_buffer(1, batch.instances.buffer.slice(..));
+        pass.set_vertex_buffer(2, batch.origins.buffer.slice(..));
+        pass.set_index_buffer(index_buffer_slice.buffer.slice(..), 0, *index_format);
+        pass.multi_draw_indexed_indirect(&batch.indirect_args.buffer, 0, batch.draw_count);
+
+        RenderCommandResult::Success
+    }
+}
+
+// Draws exactly one quad out of `TransparentChunkBatch`, at the offset
+// `queue_transparent_chunks` stashed in the phase item's `extra_index`.
+struct DrawTransparentChunkQuad;
+
+impl<P: PhaseItem> RenderCommand<P> for DrawTransparentChunkQuad {
+    type Param = (
+        SRes<RenderAssets<RenderMesh>>,
+        SRes<RenderMeshInstances>,
+        SRes<MeshAllocator>,
+        SRes<TransparentChunkBatch>,
+    );
+    type ViewQuery = ();
+    type ItemQuery = ();
+
+    #[inline]
+    fn render<'w>(
+        item: &P,
+        _view: (),
+        _item_query: Option<()>,
+        (meshes, render_mesh_instances, mesh_allocator, batch): SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let PhaseItemExtraIndex::DynamicOffset(first_instance) = item.extra_index() else {
+            return RenderCommandResult::Skip;
+        };
+
+        let batch = batch.into_inner();
+        let mesh_allocator = mesh_allocator.into_inner();
+
+        let Some(mesh_instance) = render_mesh_instances.render_mesh_queue_data(item.main_entity())
+        else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(gpu_mesh) = meshes.into_inner().get(mesh_instance.mesh_asset_id) else {
             return RenderCommandResult::Skip;
         };
         let Some(vertex_buffer_slice) =
@@ -463,33 +1330,74 @@ impl<P: PhaseItem> RenderCommand<P> for DrawMeshInstanced {
         else {
             return RenderCommandResult::Skip;
         };
-        // info!(mesh_id = ?mesh_instance.mesh_asset_id, entity = %item.entity(),"mesh id for entity");
+        let RenderMeshBufferInfo::Indexed { index_format, count } = &gpu_mesh.buffer_info else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(index_buffer_slice) =
+            mesh_allocator.mesh_index_slice(&mesh_instance.mesh_asset_id)
+        else {
+            return RenderCommandResult::Skip;
+        };
 
         pass.set_vertex_buffer(0, vertex_buffer_slice.buffer.slice(..));
-        pass.set_vertex_buffer(1, instance_buffer.buffer.slice(..));
-
-        match &gpu_mesh.buffer_info {
-            RenderMeshBufferInfo::Indexed {
-                index_format,
-                count,
-            } => {
-                let Some(index_buffer_slice) =
-                    mesh_allocator.mesh_index_slice(&mesh_instance.mesh_asset_id)
-                else {
-                    return RenderCommandResult::Skip;
-                };
-
-                pass.set_index_buffer(index_buffer_slice.buffer.slice(..), 0, *index_format);
-                pass.draw_indexed(
-                    index_buffer_slice.range.start..(index_buffer_slice.range.start + count),
-                    vertex_buffer_slice.range.start as i32,
-                    0..instance_buffer.length as u32,
-                );
-            }
-            RenderMeshBufferInfo::NonIndexed => {
-                pass.draw(vertex_buffer_slice.range, 0..instance_buffer.length as u32);
-            }
+        pass.set_vertex_buffer(1, batch.instances.buffer.slice(..));
+        pass.set_vertex_buffer(2, batch.origins.buffer.slice(..));
+        pass.set_index_buffer(index_buffer_slice.buffer.slice(..), 0, *index_format);
+        pass.draw_indexed(
+            index_buffer_slice.range.start..(index_buffer_slice.range.start + count),
+            vertex_buffer_slice.range.start as i32,
+            first_instance..(first_instance + 1),
+        );
+
+        RenderCommandResult::Success
+    }
+}
+
+// Draws a 2-vertex line list, one line per instance in `GpuChunkBatch`,
+// covering every chunk's opaque quads in one non-indirect `draw` call. The
+// placeholder mesh's own vertex buffer is still bound at slot 0, since
+// `QuadDebugPipeline` was specialized from the same mesh layout.
+struct DrawQuadDebugLines;
+
+impl<P: PhaseItem> RenderCommand<P> for DrawQuadDebugLines {
+    type Param = (
+        SRes<RenderAssets<RenderMesh>>,
+        SRes<RenderMeshInstances>,
+        SRes<MeshAllocator>,
+        SRes<GpuChunkBatch>,
+    );
+    type ViewQuery = ();
+    type ItemQuery = ();
+
+    #[inline]
+    fn render<'w>(
+        item: &P,
+        _view: (),
+        _item_query: Option<()>,
+        (meshes, render_mesh_instances, mesh_allocator, batch): SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let batch = batch.into_inner();
+        let mesh_allocator = mesh_allocator.into_inner();
+
+        let Some(mesh_instance) = render_mesh_instances.render_mesh_queue_data(item.main_entity())
+        else {
+            return RenderCommandResult::Skip;
+        };
+        if meshes.into_inner().get(mesh_instance.mesh_asset_id).is_none() {
+            return RenderCommandResult::Skip;
         }
+        let Some(vertex_buffer_slice) =
+            mesh_allocator.mesh_vertex_slice(&mesh_instance.mesh_asset_id)
+        else {
+            return RenderCommandResult::Skip;
+        };
+
+        pass.set_vertex_buffer(0, vertex_buffer_slice.buffer.slice(..));
+        pass.set_vertex_buffer(1, batch.instances.buffer.slice(..));
+        pass.set_vertex_buffer(2, batch.origins.buffer.slice(..));
+        pass.draw(0..2, 0..batch.total_instances);
+
         RenderCommandResult::Success
     }
 }